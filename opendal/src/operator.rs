@@ -15,9 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::ffi::c_void;
 use std::os::raw::c_char;
+use std::str::FromStr;
 use std::sync::LazyLock;
 
 use ::opendal as core;
@@ -29,6 +31,128 @@ static RUNTIME: LazyLock<tokio::runtime::Runtime> = LazyLock::new(|| {
         .unwrap()
 });
 
+thread_local! {
+    static LAST_ERROR: Cell<opendal_code> = const { Cell::new(opendal_code::OPENDAL_OK) };
+}
+
+/// Error classification surfaced to C callers, mirrored from
+/// [`core::ErrorKind`]. Every fallible FFI entrypoint in this module sets
+/// the thread-local last-error code before returning its `-1`/`NULL`
+/// sentinel, so callers can recover the reason via [`opendal_error_last`]
+/// instead of only learning that *something* failed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum opendal_code {
+    OPENDAL_OK = 0,
+    OPENDAL_UNEXPECTED,
+    OPENDAL_UNSUPPORTED,
+    OPENDAL_CONFIG_INVALID,
+    OPENDAL_NOT_FOUND,
+    OPENDAL_PERMISSION_DENIED,
+    OPENDAL_IS_A_DIRECTORY,
+    OPENDAL_NOT_A_DIRECTORY,
+    OPENDAL_ALREADY_EXISTS,
+    OPENDAL_RATE_LIMITED,
+    OPENDAL_INSUFFICIENT_STORAGE,
+    OPENDAL_CONDITION_NOT_MATCH,
+    OPENDAL_CONTENT_TRUNCATED,
+    OPENDAL_RANGE_NOT_SATISFIED,
+    OPENDAL_INVALID_INPUT,
+}
+
+impl From<core::ErrorKind> for opendal_code {
+    fn from(kind: core::ErrorKind) -> Self {
+        match kind {
+            core::ErrorKind::Unsupported => opendal_code::OPENDAL_UNSUPPORTED,
+            core::ErrorKind::ConfigInvalid => opendal_code::OPENDAL_CONFIG_INVALID,
+            core::ErrorKind::NotFound => opendal_code::OPENDAL_NOT_FOUND,
+            core::ErrorKind::PermissionDenied => opendal_code::OPENDAL_PERMISSION_DENIED,
+            core::ErrorKind::IsADirectory => opendal_code::OPENDAL_IS_A_DIRECTORY,
+            core::ErrorKind::NotADirectory => opendal_code::OPENDAL_NOT_A_DIRECTORY,
+            core::ErrorKind::AlreadyExists => opendal_code::OPENDAL_ALREADY_EXISTS,
+            core::ErrorKind::RateLimited => opendal_code::OPENDAL_RATE_LIMITED,
+            core::ErrorKind::InsufficientStorage => opendal_code::OPENDAL_INSUFFICIENT_STORAGE,
+            core::ErrorKind::ConditionNotMatch => opendal_code::OPENDAL_CONDITION_NOT_MATCH,
+            core::ErrorKind::ContentTruncated => opendal_code::OPENDAL_CONTENT_TRUNCATED,
+            core::ErrorKind::RangeNotSatisfied => opendal_code::OPENDAL_RANGE_NOT_SATISFIED,
+            _ => opendal_code::OPENDAL_UNEXPECTED,
+        }
+    }
+}
+
+fn set_last_error(code: opendal_code) {
+    LAST_ERROR.with(|cell| cell.set(code));
+}
+
+fn set_last_error_from(err: &core::Error) {
+    set_last_error(err.kind().into());
+}
+
+/// Returns the [`opendal_code`] for the most recently invoked fallible FFI
+/// entrypoint on the current thread. Every such entrypoint resets this to
+/// `OPENDAL_OK` as soon as its arguments are validated and overwrites it if
+/// the call then fails, so it always reflects the outcome of the call that
+/// just returned, never a stale code left over from an earlier one.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_error_last() -> opendal_code {
+    LAST_ERROR.with(|cell| cell.get())
+}
+
+pub struct opendal_operator {
+    op: *mut c_void,
+}
+
+impl opendal_operator {
+    pub(crate) fn deref(&self) -> &core::BlockingOperator {
+        // Safety: the op should never be null once constructed
+        unsafe { &*(self.op as *const core::BlockingOperator) }
+    }
+}
+
+pub struct opendal_lister {
+    lister: *mut c_void,
+}
+
+impl opendal_lister {
+    pub(crate) fn deref_mut(&mut self) -> &mut core::BlockingLister {
+        // Safety: the lister should never be null once constructed
+        unsafe { &mut *(self.lister as *mut core::BlockingLister) }
+    }
+}
+
+pub struct opendal_entry {
+    entry: *mut c_void,
+    path: std::ffi::CString,
+}
+
+impl opendal_entry {
+    pub(crate) fn deref(&self) -> &core::Entry {
+        // Safety: the entry should never be null once constructed
+        unsafe { &*(self.entry as *const core::Entry) }
+    }
+}
+
+/// File-vs-directory classification for an [`opendal_entry`], mirrored from
+/// `core::EntryMode`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum opendal_entry_mode {
+    OPENDAL_ENTRY_MODE_FILE = 0,
+    OPENDAL_ENTRY_MODE_DIR = 1,
+    OPENDAL_ENTRY_MODE_UNKNOWN = 2,
+}
+
+pub struct opendal_metadata {
+    metadata: *mut c_void,
+}
+
+impl opendal_metadata {
+    pub(crate) fn deref(&self) -> &core::Metadata {
+        // Safety: the metadata should never be null once constructed
+        unsafe { &*(self.metadata as *const core::Metadata) }
+    }
+}
+
 pub struct opendal_writer {
     inner: *mut c_void,
     writer: *mut c_void,
@@ -37,6 +161,10 @@ pub struct opendal_writer {
 pub struct opendal_reader {
     inner: *mut c_void,
     reader: *mut c_void,
+    // Tracks how many bytes have been streamed out so far, so sequential
+    // `opendal_reader_read` calls advance through the object instead of
+    // always re-reading the same `0..len` range.
+    pos: Cell<u64>,
 }
 
 impl opendal_reader {
@@ -55,6 +183,16 @@ impl opendal_writer {
     }
 }
 
+/// Converts a NUL-terminated C string into a `&str`, without aborting the
+/// process if it turns out not to be valid UTF-8 (a perfectly legal
+/// Linux path may not be). Callers translate `Err` into
+/// `OPENDAL_INVALID_INPUT` and a `-1`/`NULL` return.
+unsafe fn c_str_to_str<'a>(ptr: *const c_char) -> Result<&'a str, ()> {
+    unsafe { std::ffi::CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| ())
+}
+
 fn build_operator(
     schema: core::Scheme,
     map: HashMap<String, String>,
@@ -70,61 +208,200 @@ fn build_operator(
     Ok(op)
 }
 
+/// Build an [`opendal_operator`] for the given scheme from a flat list of
+/// `keys`/`values` config pairs, e.g. `("root", "/tmp/opendal/")` for `fs`
+/// or `("bucket", "my-bucket")` for `s3`. Mirrors the OpenDAL C binding's
+/// `opendal_operator_new`, so callers can target any backend supported by
+/// `core::Scheme` without recompiling this crate.
+///
+/// Returns `NULL` if `scheme` is not a valid [`core::Scheme`] or the
+/// operator fails to build.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn opendal_writer(path: *const c_char) -> *mut opendal_writer {
-    assert!(!path.is_null());
-    let path = unsafe {
-        std::ffi::CStr::from_ptr(path)
-            .to_str()
-            .expect("Invalid UTF-8 string")
+pub unsafe extern "C" fn opendal_operator_new(
+    scheme: *const c_char,
+    keys: *const *const c_char,
+    values: *const *const c_char,
+    n: usize,
+) -> *mut opendal_operator {
+    assert!(!scheme.is_null());
+    set_last_error(opendal_code::OPENDAL_OK);
+    let scheme = match unsafe { c_str_to_str(scheme) } {
+        Ok(scheme) => scheme,
+        Err(()) => {
+            set_last_error(opendal_code::OPENDAL_INVALID_INPUT);
+            return std::ptr::null_mut();
+        }
+    };
+    let scheme = match core::Scheme::from_str(scheme) {
+        Ok(scheme) => scheme,
+        Err(err) => {
+            set_last_error_from(&err);
+            return std::ptr::null_mut();
+        }
     };
-    let scheme = core::Scheme::Fs;
 
     let mut map = HashMap::<String, String>::default();
-    map.insert("root".to_string(), "/tmp/opendal/".to_string());
+    if n > 0 {
+        assert!(!keys.is_null());
+        assert!(!values.is_null());
+        let keys = unsafe { std::slice::from_raw_parts(keys, n) };
+        let values = unsafe { std::slice::from_raw_parts(values, n) };
+        for (key, value) in keys.iter().zip(values.iter()) {
+            assert!(!key.is_null());
+            assert!(!value.is_null());
+            let key = unsafe { std::ffi::CStr::from_ptr(*key).to_str() };
+            let value = unsafe { std::ffi::CStr::from_ptr(*value).to_str() };
+            match (key, value) {
+                (Ok(key), Ok(value)) => {
+                    map.insert(key.to_string(), value.to_string());
+                }
+                _ => {
+                    set_last_error(opendal_code::OPENDAL_INVALID_INPUT);
+                    return std::ptr::null_mut();
+                }
+            }
+        }
+    }
+
     let op = match build_operator(scheme, map) {
         Ok(op) => op,
-        Err(_) => return std::ptr::null_mut(),
+        Err(err) => {
+            set_last_error_from(&err);
+            return std::ptr::null_mut();
+        }
+    };
+    Box::into_raw(Box::new(opendal_operator {
+        op: Box::into_raw(Box::new(op.blocking())) as _,
+    }))
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_operator_free(op: *mut opendal_operator) {
+    assert!(!op.is_null());
+    unsafe {
+        drop(Box::from_raw((*op).op as *mut core::BlockingOperator));
+        drop(Box::from_raw(op));
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_operator_writer(
+    op: *mut opendal_operator,
+    path: *const c_char,
+) -> *mut opendal_writer {
+    assert!(!op.is_null());
+    assert!(!path.is_null());
+    set_last_error(opendal_code::OPENDAL_OK);
+    let op = unsafe { &*op };
+    let path = match unsafe { c_str_to_str(path) } {
+        Ok(path) => path,
+        Err(()) => {
+            set_last_error(opendal_code::OPENDAL_INVALID_INPUT);
+            return std::ptr::null_mut();
+        }
     };
-    let writer = match op.blocking().writer(path) {
+    let writer = match op.deref().writer(path) {
         Ok(w) => w,
-        Err(_) => return std::ptr::null_mut(),
+        Err(err) => {
+            set_last_error_from(&err);
+            return std::ptr::null_mut();
+        }
     };
     Box::into_raw(Box::new(opendal_writer {
-        inner: Box::into_raw(Box::new(op.blocking())) as _,
+        inner: Box::into_raw(Box::new(op.deref().clone())) as _,
         writer: Box::into_raw(Box::new(writer)) as _,
     }))
 }
 
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn opendal_reader(path: *const c_char) -> *mut opendal_reader {
+pub unsafe extern "C" fn opendal_operator_reader(
+    op: *mut opendal_operator,
+    path: *const c_char,
+) -> *mut opendal_reader {
+    assert!(!op.is_null());
     assert!(!path.is_null());
-    let path = unsafe {
-        std::ffi::CStr::from_ptr(path)
-            .to_str()
-            .expect("Invalid UTF-8 string")
+    set_last_error(opendal_code::OPENDAL_OK);
+    let op = unsafe { &*op };
+    let path = match unsafe { c_str_to_str(path) } {
+        Ok(path) => path,
+        Err(()) => {
+            set_last_error(opendal_code::OPENDAL_INVALID_INPUT);
+            return std::ptr::null_mut();
+        }
     };
-    let scheme = core::Scheme::Fs;
-
-    let mut map = HashMap::<String, String>::default();
-    map.insert("root".to_string(), "/tmp/opendal/".to_string());
-    let op = match build_operator(scheme, map) {
-        Ok(op) => op,
-        Err(_) => return std::ptr::null_mut(),
-    };
-    if !op.blocking().exists(path).unwrap_or(false) {
-        return std::ptr::null_mut();
+    match op.deref().exists(path) {
+        Ok(true) => {}
+        Ok(false) => {
+            set_last_error(opendal_code::OPENDAL_NOT_FOUND);
+            return std::ptr::null_mut();
+        }
+        Err(err) => {
+            set_last_error_from(&err);
+            return std::ptr::null_mut();
+        }
     }
-    let reader = match op.blocking().reader(path) {
+    let reader = match op.deref().reader(path) {
         Ok(r) => r,
-        Err(_) => return std::ptr::null_mut(),
+        Err(err) => {
+            set_last_error_from(&err);
+            return std::ptr::null_mut();
+        }
     };
     Box::into_raw(Box::new(opendal_reader {
-        inner: Box::into_raw(Box::new(op.blocking())) as _,
+        inner: Box::into_raw(Box::new(op.deref().clone())) as _,
         reader: Box::into_raw(Box::new(reader)) as _,
+        pos: Cell::new(0),
     }))
 }
 
+fn fs_operator_at_tmp() -> core::Result<core::Operator> {
+    let mut map = HashMap::<String, String>::default();
+    map.insert("root".to_string(), "/tmp/opendal/".to_string());
+    build_operator(core::Scheme::Fs, map)
+}
+
+/// Deprecated: writes into the hardcoded `fs` backend rooted at
+/// `/tmp/opendal/`. Prefer [`opendal_operator_new`] + [`opendal_operator_writer`]
+/// to target an arbitrary backend.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_writer(path: *const c_char) -> *mut opendal_writer {
+    assert!(!path.is_null());
+    let op = match fs_operator_at_tmp() {
+        Ok(op) => op,
+        Err(err) => {
+            set_last_error_from(&err);
+            return std::ptr::null_mut();
+        }
+    };
+    let mut op = opendal_operator {
+        op: Box::into_raw(Box::new(op.blocking())) as _,
+    };
+    let writer = unsafe { opendal_operator_writer(&mut op, path) };
+    drop(unsafe { Box::from_raw(op.op as *mut core::BlockingOperator) });
+    writer
+}
+
+/// Deprecated: reads from the hardcoded `fs` backend rooted at
+/// `/tmp/opendal/`. Prefer [`opendal_operator_new`] + [`opendal_operator_reader`]
+/// to target an arbitrary backend.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_reader(path: *const c_char) -> *mut opendal_reader {
+    assert!(!path.is_null());
+    let op = match fs_operator_at_tmp() {
+        Ok(op) => op,
+        Err(err) => {
+            set_last_error_from(&err);
+            return std::ptr::null_mut();
+        }
+    };
+    let mut op = opendal_operator {
+        op: Box::into_raw(Box::new(op.blocking())) as _,
+    };
+    let reader = unsafe { opendal_operator_reader(&mut op, path) };
+    drop(unsafe { Box::from_raw(op.op as *mut core::BlockingOperator) });
+    reader
+}
+
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn opendal_writer_free(writer: *mut opendal_writer) {
     assert!(!writer.is_null());
@@ -157,14 +434,23 @@ pub unsafe extern "C" fn opendal_writer_write(
 ) -> isize {
     assert!(!data.is_null());
     assert!(!writer.is_null());
+    set_last_error(opendal_code::OPENDAL_OK);
     let writer = unsafe { &mut *writer };
     let slice = unsafe { std::slice::from_raw_parts(data, len) };
     match writer.deref_mut().write(slice) {
         Ok(_) => len as isize,
-        Err(_) => -1,
+        Err(err) => {
+            set_last_error_from(&err);
+            -1
+        }
     }
 }
 
+/// Reads the next `len` bytes starting right after whatever this
+/// [`opendal_reader`] has already streamed out, advancing its internal
+/// cursor by the number of bytes returned. Repeated calls walk through the
+/// whole object instead of always re-reading `0..len`; use
+/// [`opendal_reader_read_at`] instead if you need random access.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn opendal_reader_read(
     reader: *mut opendal_reader,
@@ -172,12 +458,482 @@ pub unsafe extern "C" fn opendal_reader_read(
     len: usize,
 ) -> isize {
     if reader.is_null() || data.is_null() {
+        set_last_error(opendal_code::OPENDAL_INVALID_INPUT);
         return -1;
     }
+    let pos = unsafe { (*reader).pos.get() };
+    let size = unsafe { opendal_reader_read_at(reader, data, len, pos) };
+    if size >= 0 {
+        unsafe { (*reader).pos.set(pos + size as u64) };
+    }
+    size
+}
+
+/// Reads up to `len` bytes starting at `offset` into the object, ignoring
+/// and leaving untouched any cursor tracked by `reader`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_reader_read_at(
+    reader: *mut opendal_reader,
+    data: *mut u8,
+    len: usize,
+    offset: u64,
+) -> isize {
+    if reader.is_null() || data.is_null() {
+        set_last_error(opendal_code::OPENDAL_INVALID_INPUT);
+        return -1;
+    }
+    let Some(end) = offset.checked_add(len as u64) else {
+        set_last_error(opendal_code::OPENDAL_INVALID_INPUT);
+        return -1;
+    };
+    set_last_error(opendal_code::OPENDAL_OK);
     let reader = unsafe { &mut *reader };
     let mut buf = unsafe { std::slice::from_raw_parts_mut(data, len) };
-    match reader.deref_mut().read_into(&mut buf, ..len as u64) {
+    match reader.deref_mut().read_into(&mut buf, offset..end) {
         Ok(size) => size as isize,
-        Err(_) => -1,
+        Err(err) => {
+            set_last_error_from(&err);
+            -1
+        }
+    }
+}
+
+/// List the entries under `path` on `op`, returning an [`opendal_lister`]
+/// to walk them with [`opendal_lister_next`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_operator_list(
+    op: *mut opendal_operator,
+    path: *const c_char,
+) -> *mut opendal_lister {
+    assert!(!op.is_null());
+    assert!(!path.is_null());
+    set_last_error(opendal_code::OPENDAL_OK);
+    let op = unsafe { &*op };
+    let path = match unsafe { c_str_to_str(path) } {
+        Ok(path) => path,
+        Err(()) => {
+            set_last_error(opendal_code::OPENDAL_INVALID_INPUT);
+            return std::ptr::null_mut();
+        }
+    };
+    let lister = match op
+        .deref()
+        .lister_with(path)
+        .metakey(core::Metakey::ContentLength | core::Metakey::LastModified)
+        .call()
+    {
+        Ok(l) => l,
+        Err(err) => {
+            set_last_error_from(&err);
+            return std::ptr::null_mut();
+        }
+    };
+    Box::into_raw(Box::new(opendal_lister {
+        lister: Box::into_raw(Box::new(lister)) as _,
+    }))
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_lister_free(lister: *mut opendal_lister) {
+    assert!(!lister.is_null());
+    unsafe {
+        drop(Box::from_raw((*lister).lister as *mut core::BlockingLister));
+        drop(Box::from_raw(lister));
+    }
+}
+
+/// Advance `lister` and return the next [`opendal_entry`], or `NULL` once
+/// the listing is exhausted. On `NULL`, check [`opendal_error_last`]:
+/// `OPENDAL_OK` means end-of-listing, anything else means the underlying
+/// backend call failed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_lister_next(lister: *mut opendal_lister) -> *mut opendal_entry {
+    assert!(!lister.is_null());
+    set_last_error(opendal_code::OPENDAL_OK);
+    let lister = unsafe { &mut *lister };
+    match lister.deref_mut().next() {
+        Some(Ok(entry)) => {
+            let path = std::ffi::CString::new(entry.path()).expect("path must not contain NUL");
+            Box::into_raw(Box::new(opendal_entry {
+                entry: Box::into_raw(Box::new(entry)) as _,
+                path,
+            }))
+        }
+        Some(Err(err)) => {
+            set_last_error_from(&err);
+            std::ptr::null_mut()
+        }
+        None => std::ptr::null_mut(),
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_entry_free(entry: *mut opendal_entry) {
+    assert!(!entry.is_null());
+    unsafe {
+        drop(Box::from_raw((*entry).entry as *mut core::Entry));
+        drop(Box::from_raw(entry));
+    }
+}
+
+/// Returns the entry's path, relative to the operator's root. The pointer
+/// is valid until `entry` is freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_entry_path(entry: *mut opendal_entry) -> *const c_char {
+    assert!(!entry.is_null());
+    let entry = unsafe { &*entry };
+    entry.path.as_ptr()
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_entry_mode(entry: *mut opendal_entry) -> opendal_entry_mode {
+    assert!(!entry.is_null());
+    let entry = unsafe { &*entry };
+    let mode = entry.deref().metadata().mode();
+    if mode.is_dir() {
+        opendal_entry_mode::OPENDAL_ENTRY_MODE_DIR
+    } else if mode.is_file() {
+        opendal_entry_mode::OPENDAL_ENTRY_MODE_FILE
+    } else {
+        opendal_entry_mode::OPENDAL_ENTRY_MODE_UNKNOWN
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_entry_content_length(entry: *mut opendal_entry) -> u64 {
+    assert!(!entry.is_null());
+    let entry = unsafe { &*entry };
+    entry.deref().metadata().content_length()
+}
+
+/// Query metadata for `path` without reading its content, so callers can
+/// size a buffer before calling [`opendal_operator_reader`] or cheaply
+/// check existence and type. Returns `NULL` if `path` does not exist or
+/// the stat call fails; check [`opendal_error_last`] to tell them apart.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_operator_stat(
+    op: *mut opendal_operator,
+    path: *const c_char,
+) -> *mut opendal_metadata {
+    assert!(!op.is_null());
+    assert!(!path.is_null());
+    set_last_error(opendal_code::OPENDAL_OK);
+    let op = unsafe { &*op };
+    let path = match unsafe { c_str_to_str(path) } {
+        Ok(path) => path,
+        Err(()) => {
+            set_last_error(opendal_code::OPENDAL_INVALID_INPUT);
+            return std::ptr::null_mut();
+        }
+    };
+    let metadata = match op.deref().stat(path) {
+        Ok(m) => m,
+        Err(err) => {
+            set_last_error_from(&err);
+            return std::ptr::null_mut();
+        }
+    };
+    Box::into_raw(Box::new(opendal_metadata {
+        metadata: Box::into_raw(Box::new(metadata)) as _,
+    }))
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_metadata_free(metadata: *mut opendal_metadata) {
+    assert!(!metadata.is_null());
+    unsafe {
+        drop(Box::from_raw((*metadata).metadata as *mut core::Metadata));
+        drop(Box::from_raw(metadata));
+    }
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_metadata_content_length(metadata: *mut opendal_metadata) -> u64 {
+    assert!(!metadata.is_null());
+    let metadata = unsafe { &*metadata };
+    metadata.deref().content_length()
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_metadata_is_dir(metadata: *mut opendal_metadata) -> bool {
+    assert!(!metadata.is_null());
+    let metadata = unsafe { &*metadata };
+    metadata.deref().is_dir()
+}
+
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_metadata_is_file(metadata: *mut opendal_metadata) -> bool {
+    assert!(!metadata.is_null());
+    let metadata = unsafe { &*metadata };
+    metadata.deref().is_file()
+}
+
+/// Returns the last-modified time as unix milliseconds, or `-1` if the
+/// backend did not report one.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn opendal_metadata_last_modified(metadata: *mut opendal_metadata) -> i64 {
+    assert!(!metadata.is_null());
+    let metadata = unsafe { &*metadata };
+    match metadata.deref().last_modified() {
+        Some(dt) => dt.timestamp_millis(),
+        None => -1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    fn memory_operator() -> *mut opendal_operator {
+        let scheme = CString::new("memory").unwrap();
+        let op = unsafe {
+            opendal_operator_new(scheme.as_ptr(), std::ptr::null(), std::ptr::null(), 0)
+        };
+        assert!(!op.is_null());
+        op
+    }
+
+    #[test]
+    fn operator_new_builds_the_requested_backend() {
+        let scheme = CString::new("memory").unwrap();
+        let op = unsafe {
+            opendal_operator_new(scheme.as_ptr(), std::ptr::null(), std::ptr::null(), 0)
+        };
+        assert!(!op.is_null());
+        unsafe { opendal_operator_free(op) };
+    }
+
+    #[test]
+    fn operator_new_rejects_unknown_scheme() {
+        let scheme = CString::new("not-a-real-scheme").unwrap();
+        let op = unsafe {
+            opendal_operator_new(scheme.as_ptr(), std::ptr::null(), std::ptr::null(), 0)
+        };
+        assert!(op.is_null());
+    }
+
+    #[test]
+    fn operator_new_takes_config_keys_and_values() {
+        let scheme = CString::new("fs").unwrap();
+        let key = CString::new("root").unwrap();
+        let value = CString::new("/tmp/opendal-chunk0-1-test/").unwrap();
+        let keys = [key.as_ptr()];
+        let values = [value.as_ptr()];
+        let op = unsafe {
+            opendal_operator_new(scheme.as_ptr(), keys.as_ptr(), values.as_ptr(), keys.len())
+        };
+        assert!(!op.is_null());
+        unsafe { opendal_operator_free(op) };
+    }
+
+    #[test]
+    fn error_last_reports_not_found_for_a_missing_path() {
+        let op = memory_operator();
+        let path = CString::new("chunk0-2-missing.bin").unwrap();
+
+        unsafe {
+            let reader = opendal_operator_reader(op, path.as_ptr());
+            assert!(reader.is_null());
+            assert_eq!(opendal_error_last(), opendal_code::OPENDAL_NOT_FOUND);
+            opendal_operator_free(op);
+        }
+    }
+
+    #[test]
+    fn error_last_is_ok_after_a_successful_call_and_rejects_non_utf8_path() {
+        let op = memory_operator();
+        let path = CString::new("chunk0-2-ok.bin").unwrap();
+
+        unsafe {
+            let writer = opendal_operator_writer(op, path.as_ptr());
+            assert!(!writer.is_null());
+            assert_eq!(opendal_error_last(), opendal_code::OPENDAL_OK);
+            opendal_writer_free(writer);
+
+            // A lone continuation byte is never valid UTF-8, but is a
+            // perfectly legal byte sequence in a Linux path.
+            let bad_path = std::ffi::CString::new(vec![0x80]).unwrap();
+            let writer = opendal_operator_writer(op, bad_path.as_ptr());
+            assert!(writer.is_null());
+            assert_eq!(opendal_error_last(), opendal_code::OPENDAL_INVALID_INPUT);
+
+            opendal_operator_free(op);
+        }
+    }
+
+    #[test]
+    fn lister_walks_entries_with_mode_and_content_length() {
+        let op = memory_operator();
+        let a = CString::new("chunk0-3/a.txt").unwrap();
+        let b = CString::new("chunk0-3/b.txt").unwrap();
+        let dir = CString::new("chunk0-3/").unwrap();
+
+        unsafe {
+            let writer = opendal_operator_writer(op, a.as_ptr());
+            assert!(!writer.is_null());
+            assert_eq!(opendal_writer_write(writer, b"hello".as_ptr(), 5), 5);
+            opendal_writer_free(writer);
+
+            let writer = opendal_operator_writer(op, b.as_ptr());
+            assert!(!writer.is_null());
+            assert_eq!(opendal_writer_write(writer, b"hi".as_ptr(), 2), 2);
+            opendal_writer_free(writer);
+
+            let lister = opendal_operator_list(op, dir.as_ptr());
+            assert!(!lister.is_null());
+
+            let mut seen = std::collections::HashMap::new();
+            loop {
+                let entry = opendal_lister_next(lister);
+                if entry.is_null() {
+                    assert_eq!(opendal_error_last(), opendal_code::OPENDAL_OK);
+                    break;
+                }
+                let path = std::ffi::CStr::from_ptr(opendal_entry_path(entry))
+                    .to_str()
+                    .unwrap()
+                    .to_string();
+                if opendal_entry_mode(entry) == opendal_entry_mode::OPENDAL_ENTRY_MODE_FILE {
+                    seen.insert(path, opendal_entry_content_length(entry));
+                }
+                opendal_entry_free(entry);
+            }
+
+            // This is the regression the Metakey fix is for: without
+            // requesting ContentLength up front, reading it back here
+            // would panic in debug builds and silently be `0` in release.
+            assert_eq!(seen.get("chunk0-3/a.txt"), Some(&5));
+            assert_eq!(seen.get("chunk0-3/b.txt"), Some(&2));
+
+            opendal_lister_free(lister);
+            opendal_operator_free(op);
+        }
+    }
+
+    #[test]
+    fn operator_list_rejects_non_utf8_path() {
+        let op = memory_operator();
+        let bad_path = std::ffi::CString::new(vec![0x80]).unwrap();
+
+        unsafe {
+            let lister = opendal_operator_list(op, bad_path.as_ptr());
+            assert!(lister.is_null());
+            assert_eq!(opendal_error_last(), opendal_code::OPENDAL_INVALID_INPUT);
+            opendal_operator_free(op);
+        }
+    }
+
+    #[test]
+    fn operator_stat_reports_size_and_kind_for_an_existing_file() {
+        let op = memory_operator();
+        let path = CString::new("chunk0-4.bin").unwrap();
+        let content = b"0123456789";
+
+        unsafe {
+            let writer = opendal_operator_writer(op, path.as_ptr());
+            assert!(!writer.is_null());
+            assert_eq!(
+                opendal_writer_write(writer, content.as_ptr(), content.len()),
+                content.len() as isize
+            );
+            opendal_writer_free(writer);
+
+            let metadata = opendal_operator_stat(op, path.as_ptr());
+            assert!(!metadata.is_null());
+            assert_eq!(
+                opendal_metadata_content_length(metadata),
+                content.len() as u64
+            );
+            assert!(opendal_metadata_is_file(metadata));
+            assert!(!opendal_metadata_is_dir(metadata));
+            opendal_metadata_free(metadata);
+
+            opendal_operator_free(op);
+        }
+    }
+
+    #[test]
+    fn operator_stat_returns_null_for_a_missing_path() {
+        let op = memory_operator();
+        let path = CString::new("chunk0-4-missing.bin").unwrap();
+
+        unsafe {
+            let metadata = opendal_operator_stat(op, path.as_ptr());
+            assert!(metadata.is_null());
+            assert_eq!(opendal_error_last(), opendal_code::OPENDAL_NOT_FOUND);
+            opendal_operator_free(op);
+        }
+    }
+
+    #[test]
+    fn reader_read_at_rejects_offset_len_overflow() {
+        let op = memory_operator();
+        let path = CString::new("chunk0-5-overflow.bin").unwrap();
+
+        unsafe {
+            let writer = opendal_operator_writer(op, path.as_ptr());
+            assert!(!writer.is_null());
+            assert_eq!(opendal_writer_write(writer, b"x".as_ptr(), 1), 1);
+            opendal_writer_free(writer);
+
+            let reader = opendal_operator_reader(op, path.as_ptr());
+            assert!(!reader.is_null());
+            let mut buf = [0u8; 4];
+            let n = opendal_reader_read_at(reader, buf.as_mut_ptr(), buf.len(), u64::MAX - 1);
+            assert_eq!(n, -1);
+            assert_eq!(opendal_error_last(), opendal_code::OPENDAL_INVALID_INPUT);
+
+            opendal_reader_free(reader);
+            opendal_operator_free(op);
+        }
+    }
+
+    #[test]
+    fn reader_read_streams_across_calls_and_read_at_is_position_independent() {
+        let op = memory_operator();
+        let path = CString::new("chunk0-5.bin").unwrap();
+        let content = b"abcdefghijklmnopqrstuvwxyz";
+
+        unsafe {
+            let writer = opendal_operator_writer(op, path.as_ptr());
+            assert!(!writer.is_null());
+            assert_eq!(
+                opendal_writer_write(writer, content.as_ptr(), content.len()),
+                content.len() as isize
+            );
+            opendal_writer_free(writer);
+
+            // A buffer smaller than the object forces several
+            // `opendal_reader_read` calls; each one must pick up where the
+            // last left off instead of re-reading from byte 0.
+            let reader = opendal_operator_reader(op, path.as_ptr());
+            assert!(!reader.is_null());
+            let mut out = Vec::new();
+            let mut buf = [0u8; 7];
+            loop {
+                let n = opendal_reader_read(reader, buf.as_mut_ptr(), buf.len());
+                assert!(n >= 0, "read failed: {:?}", opendal_error_last());
+                if n == 0 {
+                    break;
+                }
+                out.extend_from_slice(&buf[..n as usize]);
+            }
+            assert_eq!(out, content);
+
+            // Positional reads must return the same bytes for the same
+            // range no matter how far the streaming cursor has advanced,
+            // and must not perturb that cursor.
+            let mut mid = [0u8; 5];
+            let n = opendal_reader_read_at(reader, mid.as_mut_ptr(), mid.len(), 10);
+            assert_eq!(n, mid.len() as isize);
+            assert_eq!(&mid, &content[10..15]);
+            let n = opendal_reader_read_at(reader, mid.as_mut_ptr(), mid.len(), 10);
+            assert_eq!(n, mid.len() as isize);
+            assert_eq!(&mid, &content[10..15]);
+
+            opendal_reader_free(reader);
+            opendal_operator_free(op);
+        }
     }
 }